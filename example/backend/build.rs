@@ -1,11 +1,14 @@
 use std::path::PathBuf;
 use std::process;
-use web_bundler::WebBundlerOpt;
+use web_bundler::{Backend, JsFormat, OptimizationPasses, WebBundlerOpt};
 
 fn main() {
     let out_dir =
         PathBuf::from(std::env::var("OUT_DIR").expect("expected OUT_DIR to be set by Cargo"));
 
+    let release =
+        std::env::var("PROFILE").expect("expected PROFILE to be set by Cargo") != "debug";
+
     let opt = WebBundlerOpt {
         src_dir: PathBuf::from("../frontend"),
         dist_dir: out_dir.join("ui"),
@@ -13,9 +16,14 @@ fn main() {
         base_url: Some("/".into()),
         wasm_version: std::env::var("CARGO_PKG_VERSION")
             .expect("expected CARGO_PKG_VERSION to be set by Cargo"),
-        release: std::env::var("PROFILE").expect("expected PROFILE to be set by Cargo") != "debug",
+        release,
         workspace_root: PathBuf::from(".."),
         additional_watch_dirs: Vec::new(),
+        optimization: OptimizationPasses::default_for(release),
+        backend: Backend::default(),
+        js_format: JsFormat::default(),
+        fingerprint: false,
+        theme_dir: None,
     };
     match web_bundler::run(opt) {
         Ok(()) => {}
@@ -1,17 +1,40 @@
 use anyhow::{anyhow, Context, Result};
+use notify::{RecursiveMode, Watcher};
 use rand::{thread_rng, Rng};
-use std::{fs, path::PathBuf, process::Command, thread, time::Duration};
+use std::{
+    fs,
+    net::SocketAddr,
+    path::{Component, Path, PathBuf},
+    process::Command,
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
 use tera::Tera;
 use walkdir::WalkDir;
 
 /// Bundles a Seed SPA web application for publishing
 ///
-/// - This script will run wasm-pack for the indicated crate.
+/// - This script will run wasm-pack (or, with [`Backend::WasmBindgen`], cargo
+///   plus an in-process wasm-bindgen) for the indicated crate.
 /// - An index.html file will be read from the src_dir, and processed with the Tera templating engine.
 /// - The .wasm file is versioned.
 /// - Files in ./static are copied to the output without modification.
 /// - Files with a .scss extension in ./css are compiled to css.
 ///
+/// [`run`] builds once and emits `cargo:rerun-if-changed` lines, for use from
+/// a `build.rs` script. [`serve`] instead starts a long-running dev server
+/// that serves `dist_dir`, watches the source trees, and live-reloads
+/// connected browsers on every rebuild.
+///
+/// See [`WebBundlerOpt`] for the knobs covering wasm-opt optimization
+/// (`optimization`), the JS glue format (`js_format`), content-hash
+/// fingerprinting (`fingerprint`), and a themeable fallback source tree
+/// (`theme_dir`).
+///
 /// # Example index.html
 /// ```html
 /// <!DOCTYPE html>
@@ -48,17 +71,190 @@ pub struct WebBundlerOpt {
     pub workspace_root: PathBuf,
     /// Any additional directories that, if changes happen here, a rebuild is required.
     pub additional_watch_dirs: Vec<PathBuf>,
+    /// How aggressively to optimize the output wasm with binaryen's `wasm-opt`.
+    /// Use [`OptimizationPasses::default_for`] to pick a sensible level based on
+    /// the build profile.
+    pub optimization: OptimizationPasses,
+    /// Which toolchain drives the wasm + bindgen step. Defaults to
+    /// [`Backend::WasmPack`] for backwards compatibility.
+    pub backend: Backend,
+    /// The JavaScript output format for the generated glue. Defaults to
+    /// [`JsFormat::Module`].
+    pub js_format: JsFormat,
+    /// Content-hash each emitted artifact (the wasm bundle and files under
+    /// `static/`), rename it (static files to `name.<hash>.ext`, the wasm
+    /// bundle to `app-<hash>.wasm`), and rewrite references so everything
+    /// stays wired up. A `manifest.json` mapping original paths to
+    /// fingerprinted ones is written into `dist_dir` so downstream servers can
+    /// set far-future cache headers. When `false`, the `wasm_version`-based
+    /// naming is preserved exactly.
+    pub fingerprint: bool,
+    /// An optional fallback source tree beneath `src_dir`, in the style of
+    /// Zola's themes. `index.html`, `css/style.scss`, and `static/` are
+    /// resolved from `src_dir` first and fall back to `theme_dir` when absent;
+    /// `static/` directories from both are merged, with project files
+    /// overriding theme files of the same relative path.
+    pub theme_dir: Option<PathBuf>,
+}
+
+/// Maps each emitted artifact's original `dist_dir`-relative path to its
+/// fingerprinted path. Exposed to the `index.html` template as the `manifest`
+/// context value and written to disk as `manifest.json`.
+#[derive(Default)]
+struct Manifest {
+    entries: std::collections::BTreeMap<String, String>,
+}
+
+impl Manifest {
+    fn insert(&mut self, original: impl Into<String>, fingerprinted: impl Into<String>) {
+        self.entries.insert(original.into(), fingerprinted.into());
+    }
+}
+
+/// The JavaScript glue format emitted for the wasm bundle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JsFormat {
+    /// ES-module glue (`--target web`): loaded with `<script type="module">`
+    /// and started through the default `init` export.
+    Module,
+    /// Classic-script glue (`--target no-modules`): inlined into a plain
+    /// (non-module) `<script>` and started through the global `wasm_bindgen`
+    /// initializer. Works in contexts that reject module scripts.
+    NoModules,
+}
+
+impl Default for JsFormat {
+    fn default() -> Self {
+        JsFormat::Module
+    }
+}
+
+impl JsFormat {
+    /// The `--target` value wasm-pack / wasm-bindgen should use.
+    fn wasm_target(self) -> &'static str {
+        match self {
+            JsFormat::Module => "web",
+            JsFormat::NoModules => "no-modules",
+        }
+    }
+}
+
+/// Selects how the wasm module and its JavaScript bindings are produced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// Shell out to the separately-installed `wasm-pack` binary. This is the
+    /// default and retries on the `WASM_PACK_CACHE` directory race that can
+    /// occur when several build scripts run in parallel.
+    WasmPack,
+    /// Run `cargo build --target wasm32-unknown-unknown` and then invoke
+    /// `wasm-bindgen` in-process through its library API. This removes the
+    /// hard dependency on a separately-installed `wasm-pack` and sidesteps the
+    /// shared-cache race entirely.
+    WasmBindgen,
+}
+
+impl Default for Backend {
+    fn default() -> Self {
+        Backend::WasmPack
+    }
+}
+
+/// The `wasm-opt` optimization level applied to the final wasm bundle,
+/// mirroring cargo-contract's build pipeline. Each variant maps to one of
+/// binaryen's optimization flags.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OptimizationPasses {
+    /// `-O0`: no optimization. Skips `wasm-opt` entirely.
+    Zero,
+    /// `-O1`.
+    One,
+    /// `-O2`.
+    Two,
+    /// `-O3`.
+    Three,
+    /// `-O4`.
+    Four,
+    /// `-Os`: optimize for size.
+    S,
+    /// `-Oz`: optimize aggressively for size.
+    Z,
+}
+
+impl OptimizationPasses {
+    /// The default level for a build profile: `Z` (smallest) in release,
+    /// `Zero` (skip) in debug.
+    pub fn default_for(release: bool) -> Self {
+        if release {
+            OptimizationPasses::Z
+        } else {
+            OptimizationPasses::Zero
+        }
+    }
+
+    /// The `wasm-opt` flag this level corresponds to.
+    fn flag(self) -> &'static str {
+        match self {
+            OptimizationPasses::Zero => "-O0",
+            OptimizationPasses::One => "-O1",
+            OptimizationPasses::Two => "-O2",
+            OptimizationPasses::Three => "-O3",
+            OptimizationPasses::Four => "-O4",
+            OptimizationPasses::S => "-Os",
+            OptimizationPasses::Z => "-Oz",
+        }
+    }
 }
 
 pub fn run(opt: WebBundlerOpt) -> Result<()> {
     list_cargo_rerun_if_changed_files(&opt)?;
+    build(&opt, None)
+}
+
+/// Runs the full bundling pipeline. When `livereload` is `Some`, the
+/// generated `index.html` is augmented with a small client script that
+/// reconnects to the dev server and reloads the page on rebuild.
+///
+/// The pipeline builds into a staging directory and only swaps it into
+/// `dist_dir` once every step has succeeded, so a failure partway through
+/// (a Sass syntax error, say) leaves the previous `dist_dir` untouched
+/// instead of deleting it out from under a running `serve`.
+fn build(opt: &WebBundlerOpt, livereload: Option<&LiveReload>) -> Result<()> {
+    match opt.backend {
+        Backend::WasmPack => run_wasm_pack(opt, 3)?,
+        Backend::WasmBindgen => run_wasm_bindgen(opt)?,
+    }
+
+    let staging_dir = opt.tmp_dir.join("dist-staging");
+    prepare_dist_directory(&staging_dir)?;
+    let mut manifest = Manifest::default();
+    bundle_assets(opt, &staging_dir, &mut manifest)?;
+    bundle_js_snippets(opt, &staging_dir)?;
+    bundle_app_wasm(opt, &staging_dir)?;
+    optimize_app_wasm(opt, &staging_dir)?;
+    let wasm_filename = fingerprint_app_wasm(opt, &staging_dir, &mut manifest)?;
+    bundle_index_html(opt, &staging_dir, livereload, &manifest, &wasm_filename)?;
+    write_manifest(opt, &staging_dir, &manifest)?;
 
-    run_wasm_pack(&opt, 3)?;
-    prepare_dist_directory(&opt)?;
-    bundle_assets(&opt)?;
-    bundle_js_snippets(&opt)?;
-    bundle_index_html(&opt)?;
-    bundle_app_wasm(&opt)?;
+    swap_in_dist_directory(&staging_dir, &opt.dist_dir)?;
+    Ok(())
+}
+
+/// Atomically (as far as the filesystem allows) replaces `dist_dir` with the
+/// fully-built `staging_dir`. Only called once the whole pipeline has
+/// already succeeded.
+fn swap_in_dist_directory(staging_dir: &std::path::Path, dist_dir: &std::path::Path) -> Result<()> {
+    if dist_dir.is_dir() {
+        fs::remove_dir_all(dist_dir).with_context(|| {
+            format!("Failed to clear old dist directory ({})", dist_dir.display())
+        })?;
+    }
+    fs::rename(staging_dir, dist_dir).with_context(|| {
+        format!(
+            "Failed to move {} into place at {}",
+            staging_dir.display(),
+            dist_dir.display()
+        )
+    })?;
     Ok(())
 }
 
@@ -77,6 +273,11 @@ fn list_cargo_rerun_if_changed_files(opt: &WebBundlerOpt) -> Result<()> {
             println!("cargo:rerun-if-changed={}", entry.path().display());
         }
     }
+    if let Some(theme_dir) = &opt.theme_dir {
+        for entry in WalkDir::new(theme_dir).into_iter().filter_map(|e| e.ok()) {
+            println!("cargo:rerun-if-changed={}", entry.path().display());
+        }
+    }
     Ok(())
 }
 
@@ -85,7 +286,7 @@ fn run_wasm_pack(opt: &WebBundlerOpt, retries: u32) -> Result<()> {
     let output = Command::new("wasm-pack")
         .arg("build")
         .arg("--target")
-        .arg("web")
+        .arg(opt.js_format.wasm_target())
         .arg(if opt.release { "--release" } else { "--dev" })
         .arg("--no-typescript")
         .arg("--out-name")
@@ -130,43 +331,200 @@ stderr:
     }
 }
 
-fn prepare_dist_directory(opt: &WebBundlerOpt) -> Result<()> {
-    if opt.dist_dir.is_dir() {
-        fs::remove_dir_all(&opt.dist_dir).with_context(|| {
-            format!(
-                "Failed to clear old dist directory ({})",
-                opt.dist_dir.display()
-            )
+fn run_wasm_bindgen(opt: &WebBundlerOpt) -> Result<()> {
+    let target_dir = opt.workspace_root.join("web-target");
+    let profile = if opt.release { "release" } else { "debug" };
+    let crate_name = read_crate_name(&opt.src_dir)?;
+
+    let mut command = Command::new("cargo");
+    command
+        .arg("build")
+        .arg("-p")
+        .arg(&crate_name)
+        .arg("--manifest-path")
+        .arg(opt.src_dir.join("Cargo.toml"))
+        .arg("--target")
+        .arg("wasm32-unknown-unknown");
+    if opt.release {
+        command.arg("--release");
+    }
+    let status = command
+        .env("CARGO_TARGET_DIR", target_dir.as_os_str())
+        .status()
+        .context("Failed to run cargo build for the wasm target")?;
+    if !status.success() {
+        return Err(anyhow!("cargo build for wasm32-unknown-unknown failed"));
+    }
+
+    // Cargo names the artifact after the crate's lib target, with dashes
+    // replaced by underscores, so we can target it directly instead of
+    // globbing the profile directory (which may hold other .wasm artifacts
+    // from workspace members sharing this CARGO_TARGET_DIR).
+    let wasm_dir = target_dir.join("wasm32-unknown-unknown").join(profile);
+    let input_path = wasm_dir.join(format!("{}.wasm", crate_name.replace('-', "_")));
+    if !input_path.is_file() {
+        return Err(anyhow!(
+            "Expected wasm artifact not found at {}",
+            input_path.display()
+        ));
+    }
+
+    let mut bindgen = wasm_bindgen_cli_support::Bindgen::new();
+    bindgen.input_path(&input_path);
+    match opt.js_format {
+        JsFormat::Module => {
+            bindgen
+                .web(true)
+                .map_err(|e| anyhow!("Failed to configure wasm-bindgen: {}", e))?;
+        }
+        JsFormat::NoModules => {
+            bindgen
+                .no_modules(true)
+                .map_err(|e| anyhow!("Failed to configure wasm-bindgen: {}", e))?;
+        }
+    }
+    bindgen.typescript(false).out_name("package");
+    bindgen
+        .generate(&opt.tmp_dir)
+        .map_err(|e| anyhow!("wasm-bindgen failed to generate bindings: {}", e))?;
+
+    Ok(())
+}
+
+/// Reads the `[package] name` out of `src_dir/Cargo.toml` via `cargo
+/// metadata`, so the wasm-bindgen backend can pass `cargo build -p <crate>`
+/// and know exactly which `.wasm` artifact that produced. Going through
+/// cargo itself (rather than hand-parsing the manifest) gets workspace
+/// inheritance (`name.workspace = true`) and full TOML syntax for free.
+fn read_crate_name(src_dir: &std::path::Path) -> Result<String> {
+    let manifest_path = src_dir.join("Cargo.toml");
+    let metadata = cargo_metadata::MetadataCommand::new()
+        .manifest_path(&manifest_path)
+        .no_deps()
+        .exec()
+        .with_context(|| format!("Failed to read cargo metadata for {}", manifest_path.display()))?;
+
+    let package = metadata
+        .root_package()
+        .ok_or_else(|| anyhow!("No root package found in {}", manifest_path.display()))?;
+
+    Ok(package.name.clone())
+}
+
+/// Clears and recreates `dist_dir` (the staging directory during a build,
+/// never the live, already-published one).
+fn prepare_dist_directory(dist_dir: &std::path::Path) -> Result<()> {
+    if dist_dir.is_dir() {
+        fs::remove_dir_all(dist_dir).with_context(|| {
+            format!("Failed to clear old staging directory ({})", dist_dir.display())
         })?;
     }
-    fs::create_dir_all(&opt.dist_dir).with_context(|| {
+    fs::create_dir_all(dist_dir).with_context(|| {
+        format!("Failed to create the staging directory ({})", dist_dir.display())
+    })?;
+    Ok(())
+}
+
+fn bundle_assets(
+    opt: &WebBundlerOpt,
+    dist_dir: &std::path::Path,
+    manifest: &mut Manifest,
+) -> Result<()> {
+    // Copy the theme's static files first, then overlay the project's so that
+    // project files override theme files of the same relative path.
+    let mut copied = false;
+    if let Some(theme_dir) = &opt.theme_dir {
+        copied |= copy_static_dir(&theme_dir.join("static"), dist_dir)?;
+    }
+    copied |= copy_static_dir(&opt.src_dir.join("static"), dist_dir)?;
+
+    if copied && opt.fingerprint {
+        fingerprint_static_files(&dist_dir.join("static"), dist_dir, manifest)?;
+    }
+    Ok(())
+}
+
+/// Copies `src` (a `static/` directory) into `dest`, overwriting any existing
+/// files so later calls overlay earlier ones. Returns whether `src` existed.
+fn copy_static_dir(src: &std::path::Path, dest: &std::path::Path) -> Result<bool> {
+    if !src.exists() {
+        return Ok(false);
+    }
+    let options = fs_extra::dir::CopyOptions {
+        overwrite: true,
+        ..fs_extra::dir::CopyOptions::new()
+    };
+    fs_extra::dir::copy(src, dest, &options).with_context(|| {
         format!(
-            "Failed to create the dist directory ({})",
-            opt.dist_dir.display()
+            "Failed to copy static files from {} to {}",
+            src.display(),
+            dest.display()
         )
     })?;
-    Ok(())
+    Ok(true)
 }
 
-fn bundle_assets(opt: &WebBundlerOpt) -> Result<()> {
-    let src = opt.src_dir.join("static");
-    let dest = &opt.dist_dir;
-    if src.exists() {
-        fs_extra::dir::copy(&src, &dest, &fs_extra::dir::CopyOptions::new()).with_context(
-            || {
-                format!(
-                    "Failed to copy static files from {} to {}",
-                    src.display(),
-                    dest.display()
-                )
-            },
-        )?;
+/// Walks the copied `static/` tree, renaming each file to `name.<hash>.ext`
+/// and recording the original → fingerprinted mapping in `manifest`.
+fn fingerprint_static_files(
+    static_dir: &std::path::Path,
+    dist_dir: &std::path::Path,
+    manifest: &mut Manifest,
+) -> Result<()> {
+    for entry in WalkDir::new(static_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+    {
+        let path = entry.path();
+        let bytes = fs::read(path)
+            .with_context(|| format!("Failed to read {} for fingerprinting", path.display()))?;
+        let hashed = fingerprinted_name(path, &content_hash(&bytes));
+        fs::rename(path, &hashed).with_context(|| {
+            format!("Failed to rename {} to {}", path.display(), hashed.display())
+        })?;
+
+        let original_rel = path
+            .strip_prefix(dist_dir)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let hashed_rel = hashed
+            .strip_prefix(dist_dir)
+            .unwrap_or(&hashed)
+            .to_string_lossy()
+            .replace('\\', "/");
+        manifest.insert(original_rel, hashed_rel);
     }
     Ok(())
 }
 
-fn bundle_index_html(opt: &WebBundlerOpt) -> Result<()> {
-    let src_index_path = opt.src_dir.join("index.html");
+/// Resolves a source-relative path against `src_dir`, falling back to
+/// `theme_dir` when the project doesn't provide the file. Returns the
+/// `src_dir` candidate when neither exists so callers surface a sensible
+/// "file not found" error against the project tree.
+fn resolve_source(opt: &WebBundlerOpt, relative: &str) -> PathBuf {
+    let project = opt.src_dir.join(relative);
+    if project.exists() {
+        return project;
+    }
+    if let Some(theme_dir) = &opt.theme_dir {
+        let themed = theme_dir.join(relative);
+        if themed.exists() {
+            return themed;
+        }
+    }
+    project
+}
+
+fn bundle_index_html(
+    opt: &WebBundlerOpt,
+    dist_dir: &std::path::Path,
+    livereload: Option<&LiveReload>,
+    manifest: &Manifest,
+    wasm_filename: &str,
+) -> Result<()> {
+    let src_index_path = resolve_source(opt, "index.html");
     let index_html_template = fs::read_to_string(&src_index_path).with_context(|| {
         format!(
             "Failed to read {}. This should be a source code file checked into the repo.",
@@ -183,21 +541,39 @@ fn bundle_index_html(opt: &WebBundlerOpt) -> Result<()> {
             package_js_path.display()
         )
     })?;
-    let javascript = format!(
-        r#"<script type="module">{} init('app-{}.wasm'); </script>"#,
-        package_js_content, opt.wasm_version
-    );
+    let mut javascript = match opt.js_format {
+        JsFormat::Module => format!(
+            r#"<script type="module">{} init('{}'); </script>"#,
+            package_js_content, wasm_filename
+        ),
+        JsFormat::NoModules => format!(
+            r#"<script>{} wasm_bindgen('{}'); </script>"#,
+            package_js_content, wasm_filename
+        ),
+    };
+    if let Some(livereload) = livereload {
+        javascript.push_str(&livereload.client_script());
+    }
     tera_context.insert("javascript", &javascript);
 
+    // Let templates resolve fingerprinted asset paths, e.g.
+    // `{{ manifest["static/logo.png"] }}`.
+    tera_context.insert("manifest", &manifest.entries);
+
     tera_context.insert("base_url", opt.base_url.as_deref().unwrap_or("/"));
 
+    // Let a project stylesheet `@import` partials from the theme's css dir.
+    let include_paths = match &opt.theme_dir {
+        Some(theme_dir) => vec![theme_dir.join("css").display().to_string()],
+        None => Vec::new(),
+    };
     let sass_options = sass_rs::Options {
         output_style: sass_rs::OutputStyle::Compressed,
         precision: 4,
         indented_syntax: true,
-        include_paths: Vec::new(),
+        include_paths,
     };
-    let style_src_path = opt.src_dir.join("css/style.scss");
+    let style_src_path = resolve_source(opt, "css/style.scss");
     let style_css_content = sass_rs::compile_file(&style_src_path, sass_options)
         .map_err(|e| anyhow!("Sass compilation failed: {}", e))?;
 
@@ -206,7 +582,7 @@ fn bundle_index_html(opt: &WebBundlerOpt) -> Result<()> {
 
     let index_html_content = Tera::one_off(&index_html_template, &tera_context, true)?;
 
-    let dest_index_path = opt.dist_dir.join("index.html");
+    let dest_index_path = dist_dir.join("index.html");
     fs::write(&dest_index_path, index_html_content).with_context(|| {
         format!(
             "Failed to write the index.html file to {}",
@@ -217,9 +593,9 @@ fn bundle_index_html(opt: &WebBundlerOpt) -> Result<()> {
     Ok(())
 }
 
-fn bundle_app_wasm(opt: &WebBundlerOpt) -> Result<()> {
+fn bundle_app_wasm(opt: &WebBundlerOpt, dist_dir: &std::path::Path) -> Result<()> {
     let src = opt.tmp_dir.join("package_bg.wasm");
-    let dest = opt.dist_dir.join(format!("app-{}.wasm", opt.wasm_version));
+    let dest = dist_dir.join(format!("app-{}.wasm", opt.wasm_version));
     fs::copy(&src, &dest).with_context(|| {
         format!(
             "Failed to copy application wasm from {} to {}",
@@ -230,9 +606,134 @@ fn bundle_app_wasm(opt: &WebBundlerOpt) -> Result<()> {
     Ok(())
 }
 
-fn bundle_js_snippets(opt: &WebBundlerOpt) -> Result<()> {
+/// Fingerprints the (already optimized) wasm bundle when enabled, renaming
+/// `app-<version>.wasm` to `app-<hash>.wasm` and recording the mapping.
+/// Returns the filename the `index.html` glue should load.
+fn fingerprint_app_wasm(
+    opt: &WebBundlerOpt,
+    dist_dir: &std::path::Path,
+    manifest: &mut Manifest,
+) -> Result<String> {
+    let versioned = format!("app-{}.wasm", opt.wasm_version);
+    if !opt.fingerprint {
+        return Ok(versioned);
+    }
+
+    let src = dist_dir.join(&versioned);
+    let bytes = fs::read(&src)
+        .with_context(|| format!("Failed to read {} for fingerprinting", src.display()))?;
+    let hashed = format!("app-{}.wasm", content_hash(&bytes));
+    let dest = dist_dir.join(&hashed);
+    fs::rename(&src, &dest)
+        .with_context(|| format!("Failed to rename {} to {}", src.display(), dest.display()))?;
+
+    manifest.insert(versioned, hashed.clone());
+    Ok(hashed)
+}
+
+/// Writes the asset manifest to `dist_dir/manifest.json` when fingerprinting
+/// is enabled, so downstream servers can serve fingerprinted files with
+/// immutable, far-future cache headers.
+fn write_manifest(
+    opt: &WebBundlerOpt,
+    dist_dir: &std::path::Path,
+    manifest: &Manifest,
+) -> Result<()> {
+    if !opt.fingerprint {
+        return Ok(());
+    }
+    let dest = dist_dir.join("manifest.json");
+    let json = serde_json::to_string_pretty(&manifest.entries)
+        .context("Failed to serialize the asset manifest")?;
+    fs::write(&dest, json)
+        .with_context(|| format!("Failed to write the asset manifest to {}", dest.display()))?;
+    Ok(())
+}
+
+/// The first 8 hex characters of the SHA-256 of `bytes`.
+fn content_hash(bytes: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    let digest = Sha256::digest(bytes);
+    let mut hash = String::with_capacity(8);
+    for byte in digest.iter().take(4) {
+        hash.push_str(&format!("{:02x}", byte));
+    }
+    hash
+}
+
+/// Inserts `hash` before the file's extension: `style.css` → `style.<hash>.css`.
+fn fingerprinted_name(path: &std::path::Path, hash: &str) -> PathBuf {
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    let name = match path.extension().and_then(|e| e.to_str()) {
+        Some(ext) => format!("{}.{}.{}", stem, hash, ext),
+        None => format!("{}.{}", stem, hash),
+    };
+    path.with_file_name(name)
+}
+
+fn optimize_app_wasm(opt: &WebBundlerOpt, dist_dir: &std::path::Path) -> Result<()> {
+    if opt.optimization == OptimizationPasses::Zero {
+        return Ok(());
+    }
+
+    let dest = dist_dir.join(format!("app-{}.wasm", opt.wasm_version));
+    let before = fs::metadata(&dest).map(|m| m.len()).unwrap_or(0);
+
+    // wasm-opt writes into a separate temp file and is only swapped over
+    // `dest` on success, so a crash or truncated write mid-optimization
+    // can't corrupt the unoptimized copy bundle_app_wasm already wrote.
+    let optimized = opt.tmp_dir.join(format!("app-{}.optimized.wasm", opt.wasm_version));
+    let output = Command::new("wasm-opt")
+        .arg(opt.optimization.flag())
+        .arg("-o")
+        .arg(&optimized)
+        .arg(&dest)
+        .output();
+
+    match output {
+        Ok(output) if output.status.success() => {
+            fs::rename(&optimized, &dest).with_context(|| {
+                format!(
+                    "Failed to move optimized wasm from {} to {}",
+                    optimized.display(),
+                    dest.display()
+                )
+            })?;
+            let after = fs::metadata(&dest).map(|m| m.len()).unwrap_or(before);
+            println!(
+                "cargo:warning=wasm-opt {} reduced {} from {} to {} bytes",
+                opt.optimization.flag(),
+                dest.display(),
+                before,
+                after
+            );
+            Ok(())
+        }
+        Ok(output) => {
+            // A failed optimization pass shouldn't fail the build; keep the
+            // unoptimized copy that bundle_app_wasm already wrote.
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            println!(
+                "cargo:warning=wasm-opt failed, shipping unoptimized wasm: {}",
+                stderr.trim()
+            );
+            let _ = fs::remove_file(&optimized);
+            Ok(())
+        }
+        Err(_) => {
+            // wasm-opt isn't installed; fall back to the unoptimized copy.
+            println!(
+                "cargo:warning=wasm-opt not found on PATH, shipping unoptimized wasm. \
+                 Install binaryen to shrink release bundles."
+            );
+            Ok(())
+        }
+    }
+}
+
+fn bundle_js_snippets(opt: &WebBundlerOpt, dist_dir: &std::path::Path) -> Result<()> {
     let src = opt.tmp_dir.join("snippets");
-    let dest = &opt.dist_dir;
+    let dest = dist_dir;
 
     if src.exists() {
         fs_extra::dir::copy(&src, &dest, &fs_extra::dir::CopyOptions::new()).with_context(
@@ -247,3 +748,367 @@ fn bundle_js_snippets(opt: &WebBundlerOpt) -> Result<()> {
     }
     Ok(())
 }
+
+/// Options controlling the [`serve`] development server.
+///
+/// `serve` runs the normal bundling pipeline once up front, then keeps
+/// running: it serves the contents of `dist_dir` over HTTP, watches the
+/// source trees, and rebuilds + live-reloads connected browsers whenever
+/// a file changes. It gives Seed projects the same ergonomics as
+/// `zola serve`.
+pub struct ServeOpt {
+    /// The address the HTTP (and live-reload WebSocket) server binds to.
+    pub addr: SocketAddr,
+    /// The request path used for the live-reload WebSocket. Pick something
+    /// that won't collide with the application's own routes. Defaults to
+    /// `/__web_bundler_livereload` via [`Default`].
+    pub websocket_path: String,
+    /// How long to wait after the last filesystem event before rebuilding,
+    /// so a burst of editor saves coalesces into a single rebuild.
+    pub debounce: Duration,
+}
+
+impl Default for ServeOpt {
+    fn default() -> Self {
+        ServeOpt {
+            addr: SocketAddr::from(([127, 0, 0, 1], 8000)),
+            websocket_path: "/__web_bundler_livereload".to_owned(),
+            debounce: Duration::from_millis(300),
+        }
+    }
+}
+
+/// A duplex stream hijacked from tiny_http after a WebSocket upgrade,
+/// wrapped so that tungstenite (which needs `Read + Write`) can drive it.
+/// The newtype delegates `Read`/`Write` to the `Box<dyn ReadWrite + Send>`
+/// that tiny_http hands back.
+struct UpgradedStream(Box<dyn tiny_http::ReadWrite + Send>);
+
+impl std::io::Read for UpgradedStream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.0.read(buf)
+    }
+}
+
+impl std::io::Write for UpgradedStream {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
+/// The set of live-reload WebSocket clients currently connected to the
+/// dev server. After a successful rebuild the server broadcasts a
+/// `reload` text frame to every socket.
+#[derive(Clone, Default)]
+struct LiveReload {
+    websocket_path: String,
+    clients: Arc<Mutex<Vec<tungstenite::WebSocket<UpgradedStream>>>>,
+}
+
+impl LiveReload {
+    fn new(websocket_path: String) -> Self {
+        LiveReload {
+            websocket_path,
+            clients: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// The `<script>` snippet injected into the served `index.html`. It
+    /// opens a WebSocket back to the dev server and reloads the page when
+    /// it receives a `reload` message.
+    fn client_script(&self) -> String {
+        format!(
+            r#"<script>(function(){{var s=new WebSocket((location.protocol==='https:'?'wss://':'ws://')+location.host+'{}');s.onmessage=function(e){{if(e.data==='reload'){{location.reload();}}}};s.onclose=function(){{setTimeout(function(){{location.reload();}},1000);}};}})();</script>"#,
+            self.websocket_path
+        )
+    }
+
+    fn register(&self, socket: tungstenite::WebSocket<UpgradedStream>) {
+        self.clients.lock().unwrap().push(socket);
+    }
+
+    /// Tell every connected browser to reload, dropping any socket that
+    /// has since disconnected.
+    fn broadcast_reload(&self) {
+        let mut clients = self.clients.lock().unwrap();
+        clients.retain_mut(|socket| {
+            socket
+                .send(tungstenite::Message::Text("reload".to_owned()))
+                .is_ok()
+        });
+    }
+}
+
+/// Starts a long-running development server.
+///
+/// Unlike [`run`], this never returns under normal operation: it serves
+/// `dist_dir` over HTTP, watches `src_dir` plus `additional_watch_dirs`,
+/// and rebuilds + live-reloads on every change. A rebuild that fails
+/// (for example a Sass error) is reported to stdout and the last good
+/// bundle keeps being served rather than crashing the server.
+pub fn serve(opt: WebBundlerOpt, serve_opt: ServeOpt) -> Result<()> {
+    let livereload = LiveReload::new(serve_opt.websocket_path.clone());
+
+    // Produce an initial bundle so there is something to serve right away.
+    if let Err(e) = build(&opt, Some(&livereload)) {
+        println!("Initial build failed: {e:?}");
+    }
+
+    let server = Arc::new(
+        tiny_http::Server::http(serve_opt.addr)
+            .map_err(|e| anyhow!("Failed to start dev server on {}: {}", serve_opt.addr, e))?,
+    );
+    println!("web-bundler serving {} at http://{}", opt.dist_dir.display(), serve_opt.addr);
+
+    // Serve HTTP requests on a background thread.
+    {
+        let server = Arc::clone(&server);
+        let dist_dir = opt.dist_dir.clone();
+        let livereload = livereload.clone();
+        let ws_path = serve_opt.websocket_path.clone();
+        thread::spawn(move || serve_http(&server, &dist_dir, &livereload, &ws_path));
+    }
+
+    watch_and_rebuild(&opt, &serve_opt, &livereload)
+}
+
+/// Handles incoming HTTP requests: WebSocket upgrades on `ws_path` are
+/// registered with the live-reload set, everything else is served from
+/// `dist_dir` with a directory request falling back to its `index.html`.
+fn serve_http(
+    server: &tiny_http::Server,
+    dist_dir: &std::path::Path,
+    livereload: &LiveReload,
+    ws_path: &str,
+) {
+    for request in server.incoming_requests() {
+        let url = request.url().split('?').next().unwrap_or("/").to_owned();
+
+        let is_websocket = url == ws_path
+            && request.headers().iter().any(|h| {
+                h.field.equiv("Upgrade") && h.value.as_str().eq_ignore_ascii_case("websocket")
+            });
+
+        if is_websocket {
+            accept_livereload_socket(request, livereload);
+            continue;
+        }
+
+        if let Err(e) = serve_file(request, dist_dir, &url) {
+            println!("Failed to serve {url}: {e}");
+        }
+    }
+}
+
+fn accept_livereload_socket(request: tiny_http::Request, livereload: &LiveReload) {
+    let key = request
+        .headers()
+        .iter()
+        .find(|h| h.field.equiv("Sec-WebSocket-Key"))
+        .map(|h| h.value.as_str().to_owned());
+    let key = match key {
+        Some(key) => key,
+        None => return,
+    };
+    let accept = tungstenite::handshake::derive_accept_key(key.as_bytes());
+    let accept_header =
+        match tiny_http::Header::from_bytes(&b"Sec-WebSocket-Accept"[..], accept.as_bytes()) {
+            Ok(header) => header,
+            Err(_) => return,
+        };
+    let response = tiny_http::Response::empty(101).with_header(accept_header);
+
+    // Hijack the underlying stream and complete the handshake ourselves so
+    // tungstenite can drive the socket from here on. `upgrade` writes the
+    // 101 response we built above and hands back the raw stream.
+    let stream = request.upgrade("websocket", response);
+    let socket = tungstenite::WebSocket::from_raw_socket(
+        UpgradedStream(stream),
+        tungstenite::protocol::Role::Server,
+        None,
+    );
+    livereload.register(socket);
+}
+
+fn serve_file(request: tiny_http::Request, dist_dir: &std::path::Path, url: &str) -> Result<()> {
+    let relative = url.trim_start_matches('/');
+    if !is_safe_relative_path(Path::new(relative)) {
+        return request
+            .respond(tiny_http::Response::from_string("Not Found").with_status_code(404))
+            .context("Failed to write 404 response");
+    }
+    let mut path = dist_dir.join(relative);
+    if path.is_dir() || url.ends_with('/') || relative.is_empty() {
+        path = path.join("index.html");
+    }
+    // A client-side router's deep links should still boot the app, so
+    // fall back to the root index.html when the file isn't present.
+    if !path.is_file() {
+        path = dist_dir.join("index.html");
+    }
+
+    match fs::read(&path) {
+        Ok(bytes) => {
+            let mut response = tiny_http::Response::from_data(bytes);
+            if let Some(mime) = mime_for(&path) {
+                if let Ok(header) = tiny_http::Header::from_bytes(&b"Content-Type"[..], mime.as_bytes())
+                {
+                    response.add_header(header);
+                }
+            }
+            request.respond(response).context("Failed to write response")
+        }
+        Err(_) => request
+            .respond(tiny_http::Response::from_string("Not Found").with_status_code(404))
+            .context("Failed to write 404 response"),
+    }
+}
+
+/// Rejects request paths that could escape `dist_dir`, e.g. `../../etc/passwd`
+/// or an absolute path smuggled in via the URL.
+fn is_safe_relative_path(path: &Path) -> bool {
+    path.components()
+        .all(|component| matches!(component, Component::Normal(_)))
+}
+
+fn mime_for(path: &std::path::Path) -> Option<&'static str> {
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("html") => Some("text/html; charset=utf-8"),
+        Some("js") => Some("text/javascript"),
+        Some("wasm") => Some("application/wasm"),
+        Some("css") => Some("text/css"),
+        Some("json") => Some("application/json"),
+        Some("svg") => Some("image/svg+xml"),
+        _ => None,
+    }
+}
+
+/// Watches the source trees and rebuilds on change, debouncing bursts of
+/// filesystem events into a single rebuild.
+fn watch_and_rebuild(
+    opt: &WebBundlerOpt,
+    serve_opt: &ServeOpt,
+    livereload: &LiveReload,
+) -> Result<()> {
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |res| {
+        if let Ok(event) = res {
+            let _ = tx.send(event);
+        }
+    })
+    .context("Failed to create filesystem watcher")?;
+
+    watcher
+        .watch(&opt.src_dir, RecursiveMode::Recursive)
+        .with_context(|| format!("Failed to watch {}", opt.src_dir.display()))?;
+    for dir in &opt.additional_watch_dirs {
+        watcher
+            .watch(dir, RecursiveMode::Recursive)
+            .with_context(|| format!("Failed to watch {}", dir.display()))?;
+    }
+    if let Some(theme_dir) = &opt.theme_dir {
+        watcher
+            .watch(theme_dir, RecursiveMode::Recursive)
+            .with_context(|| format!("Failed to watch {}", theme_dir.display()))?;
+    }
+
+    loop {
+        // Block until the first event, then keep draining until the stream
+        // goes quiet for `debounce` so a burst of saves is one rebuild.
+        if rx.recv().is_err() {
+            break;
+        }
+        loop {
+            match rx.recv_timeout(serve_opt.debounce) {
+                Ok(_) => continue,
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            }
+        }
+
+        println!("Change detected, rebuilding...");
+        match build(opt, Some(livereload)) {
+            Ok(()) => {
+                livereload.broadcast_reload();
+                println!("Rebuild complete.");
+            }
+            Err(e) => println!("Rebuild failed, keeping last good bundle: {e:?}"),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wasm_target_maps_js_format_to_bindgen_target() {
+        assert_eq!(JsFormat::Module.wasm_target(), "web");
+        assert_eq!(JsFormat::NoModules.wasm_target(), "no-modules");
+    }
+
+    #[test]
+    fn optimization_passes_default_for_profile() {
+        assert_eq!(OptimizationPasses::default_for(true), OptimizationPasses::Z);
+        assert_eq!(OptimizationPasses::default_for(false), OptimizationPasses::Zero);
+    }
+
+    #[test]
+    fn optimization_passes_flag() {
+        assert_eq!(OptimizationPasses::Zero.flag(), "-O0");
+        assert_eq!(OptimizationPasses::One.flag(), "-O1");
+        assert_eq!(OptimizationPasses::Two.flag(), "-O2");
+        assert_eq!(OptimizationPasses::Three.flag(), "-O3");
+        assert_eq!(OptimizationPasses::Four.flag(), "-O4");
+        assert_eq!(OptimizationPasses::S.flag(), "-Os");
+        assert_eq!(OptimizationPasses::Z.flag(), "-Oz");
+    }
+
+    #[test]
+    fn content_hash_is_stable_and_content_sensitive() {
+        let hash = content_hash(b"hello world");
+        assert_eq!(hash.len(), 8);
+        assert_eq!(hash, content_hash(b"hello world"));
+        assert_ne!(hash, content_hash(b"hello world!"));
+    }
+
+    #[test]
+    fn fingerprinted_name_inserts_hash_before_extension() {
+        assert_eq!(
+            fingerprinted_name(Path::new("style.css"), "abcd1234"),
+            PathBuf::from("style.abcd1234.css")
+        );
+        assert_eq!(
+            fingerprinted_name(Path::new("static/app.wasm"), "abcd1234"),
+            PathBuf::from("static/app.abcd1234.wasm")
+        );
+    }
+
+    #[test]
+    fn fingerprinted_name_without_extension() {
+        assert_eq!(
+            fingerprinted_name(Path::new("LICENSE"), "abcd1234"),
+            PathBuf::from("LICENSE.abcd1234")
+        );
+    }
+
+    #[test]
+    fn safe_relative_path_accepts_plain_paths() {
+        assert!(is_safe_relative_path(Path::new("index.html")));
+        assert!(is_safe_relative_path(Path::new("static/app.js")));
+        assert!(is_safe_relative_path(Path::new("")));
+    }
+
+    #[test]
+    fn safe_relative_path_rejects_traversal() {
+        assert!(!is_safe_relative_path(Path::new("../../etc/passwd")));
+        assert!(!is_safe_relative_path(Path::new("static/../../secret")));
+        assert!(!is_safe_relative_path(Path::new("/etc/passwd")));
+    }
+}
@@ -1,12 +1,21 @@
 use clap::Parser;
 use duct::cmd;
 use std::error::Error;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use web_bundler::{Backend, JsFormat, OptimizationPasses, ServeOpt, WebBundlerOpt};
 
 #[derive(Parser, Clone, Debug, PartialEq, Eq)]
 #[command()]
 pub enum Args {
     /// Do the full CI test run
     Ci,
+    /// Serve the example frontend with file watching and live-reload
+    Serve {
+        /// Address to bind the dev server to.
+        #[arg(long, default_value = "127.0.0.1:8000")]
+        addr: SocketAddr,
+    },
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -17,6 +26,28 @@ fn main() -> Result<(), Box<dyn Error>> {
             cmd!("cargo", "test", "--manifest-path", "example/Cargo.toml").run()?;
             cmd!("cargo", "test", "--manifest-path", "tests/bevy/Cargo.toml").run()?;
         }
+        Args::Serve { addr } => {
+            let opt = WebBundlerOpt {
+                src_dir: PathBuf::from("example/frontend"),
+                dist_dir: PathBuf::from("web-target/serve"),
+                tmp_dir: PathBuf::from("web-target/serve-tmp"),
+                base_url: Some("/".into()),
+                wasm_version: "dev".into(),
+                release: false,
+                workspace_root: PathBuf::from("."),
+                additional_watch_dirs: Vec::new(),
+                optimization: OptimizationPasses::default_for(false),
+                backend: Backend::default(),
+                js_format: JsFormat::default(),
+                fingerprint: false,
+                theme_dir: None,
+            };
+            let serve_opt = ServeOpt {
+                addr,
+                ..ServeOpt::default()
+            };
+            web_bundler::serve(opt, serve_opt)?;
+        }
     }
     Ok(())
 }